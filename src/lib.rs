@@ -12,7 +12,7 @@
 //! See the example included with the [`list_enabled_as_string`] function.
 
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io::{self, BufRead};
 use std::fmt::Write;
 
@@ -124,6 +124,453 @@ pub fn list_enabled_as_string_with_path(const_name: &str, cargo_toml_path: &str)
   buf
 }
 
+/// Returns the list of enabled features alongside their documentation, as `(feature, doc)` pairs.
+///
+/// The documentation for a feature is extracted from the `## ` doc comments (borrowed from the
+/// [document-features](https://crates.io/crates/document-features) convention) placed immediately
+/// above its declaration in the `[features]` section. A feature with no such comment gets an empty
+/// `String` as its doc.
+///
+/// This function should only be called in build scripts or code executed during a Cargo build process, as
+/// the required `CARGO_FEATURE_*` environment variables will be missing otherwise.
+///
+/// See also [`list_enabled_with_docs_with_path`].
+///
+/// # Panics
+///
+/// Panics if the `Cargo.toml` file cannot be read.
+pub fn list_enabled_with_docs() -> Vec<(String, String)> {
+  list_enabled_with_docs_with_path("Cargo.toml")
+}
+
+/// Returns the list of enabled features alongside their documentation, as `(feature, doc)` pairs.
+///
+/// Same as [`list_enabled_with_docs`] but allows specifying a custom path to `Cargo.toml`.
+///
+/// # Panics
+///
+/// Panics if the specified file cannot be read.
+///
+/// # Arguments
+///
+/// * `cargo_toml_path` - Path to the `Cargo.toml` file
+pub fn list_enabled_with_docs_with_path(cargo_toml_path: &str) -> Vec<(String, String)> {
+  let docs = list_docs(cargo_toml_path).unwrap();
+  let all_features: HashSet<String> = docs.iter().map(|(feature, _)| feature.clone()).collect();
+  let enabled = list_enabled_among(&all_features);
+
+  enabled
+    .into_iter()
+    .map(|feature| {
+      let doc = docs
+        .iter()
+        .find(|(key, _)| *key == feature)
+        .map(|(_, doc)| doc.clone())
+        .unwrap_or_default();
+      (feature, doc)
+    })
+    .collect()
+}
+
+/// Generates a constant declaration containing enabled Cargo features paired with their documentation.
+///
+/// It’s a wrapper around [`list_enabled_with_docs`] that provides a `String` that should be usable as is
+/// in an output file of the build script, for programs that want to print a human-readable description
+/// alongside each enabled feature rather than just its name.
+///
+/// See also [`list_enabled_as_string_with_docs_with_path`].
+///
+/// # Panics
+///
+/// Panics if the `Cargo.toml` file cannot be read.
+///
+/// # Arguments
+///
+/// * `const_name` - Name of the constant to generate.
+///
+/// # Returns
+/// A `String` containing the code for the constant declaration, like:
+/// ```
+/// String::from(r#"pub const CONST_NAME: &[(&str, &str)] = &[
+/// ("feature1", "What feature1 does."),
+/// ("feature2", ""),
+/// ];"#);
+/// ```
+pub fn list_enabled_as_string_with_docs(const_name: &str) -> String {
+  list_enabled_as_string_with_docs_with_path(const_name, "Cargo.toml")
+}
+
+/// Generates a constant declaration containing enabled Cargo features paired with their documentation.
+///
+/// Same as [`list_enabled_as_string_with_docs`] but allows specifying a custom path to `Cargo.toml`.
+///
+/// # Panics
+///
+/// Panics if the specified file cannot be read.
+///
+/// # Arguments
+/// * `const_name` - Name of the constant to generate
+/// * `cargo_toml_path` - Path to the `Cargo.toml` file
+pub fn list_enabled_as_string_with_docs_with_path(const_name: &str, cargo_toml_path: &str) -> String {
+  let enabled_features = list_enabled_with_docs_with_path(cargo_toml_path);
+  let mut buf = String::new();
+  writeln!(buf, "pub const {const_name}: &[(&str, &str)] = &[").unwrap();
+  for (feature, doc) in enabled_features {
+    let feature = escape_rust_string_literal(&feature);
+    let doc = escape_rust_string_literal(&doc);
+    writeln!(buf, r#"("{feature}", "{doc}"),"#).unwrap();
+  }
+  writeln!(buf, "];").unwrap();
+  buf
+}
+
+// Escapes `"` and `\` so `s` can be embedded in a generated Rust `"..."` string literal. `doc`
+// in particular is free-form prose pulled straight from Cargo.toml comments, so it can't be
+// assumed to already be literal-safe.
+fn escape_rust_string_literal(s: &str) -> String {
+  let mut escaped = String::with_capacity(s.len());
+  for ch in s.chars() {
+    match ch {
+      '\\' => escaped.push_str(r"\\"),
+      '"' => escaped.push_str("\\\""),
+      _ => escaped.push(ch),
+    }
+  }
+  escaped
+}
+
+/// Output format for [`list_enabled_as`] and [`list_enabled_as_with_path`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+  /// `pub const NAME: &[&str] = &[...]`, same as [`list_enabled_as_string`].
+  Slice,
+  /// A JSON array of `{"feature": ..., "doc": ...}` objects, with the doc strings from
+  /// [`list_enabled_with_docs`]. Meant to be written as its own build artifact and read by
+  /// non-Rust tooling (CI dashboards, packaging scripts), rather than `include!`d as Rust code.
+  Json,
+  /// `pub enum NAME { Variant1, Variant2, ... }` with a `NAME::all() -> &'static [NAME]` listing
+  /// every variant, for type-safe matching instead of stringly-typed lookups. Feature names are
+  /// converted to `PascalCase` for the variant names.
+  Enum,
+}
+
+/// Generates code declaring enabled Cargo features in the given [`Format`].
+///
+/// It’s a wrapper around [`list_enabled`] (or [`list_enabled_with_docs`] for [`Format::Json`])
+/// that provides a `String` usable as is in an output file of the build script. This function
+/// should only be called in build scripts or code executed during a Cargo build process, as the
+/// required `CARGO_FEATURE_*` environment variables will be missing otherwise.
+///
+/// See also [`list_enabled_as_with_path`].
+///
+/// # Panics
+///
+/// Panics if the `Cargo.toml` file cannot be read, or, for [`Format::Enum`], if two enabled
+/// features would generate the same variant name (e.g. `foo-23` and `foo23` both become `Foo23`).
+///
+/// # Arguments
+///
+/// * `const_name` - Name of the constant (or enum, for [`Format::Enum`]) to generate. Unused for [`Format::Json`].
+/// * `format` - The output format to generate.
+pub fn list_enabled_as(const_name: &str, format: Format) -> String {
+  list_enabled_as_with_path(const_name, format, "Cargo.toml")
+}
+
+/// Generates code declaring enabled Cargo features in the given [`Format`].
+///
+/// Same as [`list_enabled_as`] but allows specifying a custom path to `Cargo.toml`.
+///
+/// # Panics
+///
+/// Panics if the specified file cannot be read, or, for [`Format::Enum`], if two enabled features
+/// would generate the same variant name (e.g. `foo-23` and `foo23` both become `Foo23`).
+///
+/// # Arguments
+///
+/// * `const_name` - Name of the constant (or enum, for [`Format::Enum`]) to generate. Unused for [`Format::Json`].
+/// * `format` - The output format to generate.
+/// * `cargo_toml_path` - Path to the `Cargo.toml` file
+pub fn list_enabled_as_with_path(const_name: &str, format: Format, cargo_toml_path: &str) -> String {
+  match format {
+    Format::Slice => list_enabled_as_string_with_path(const_name, cargo_toml_path),
+    Format::Json => list_enabled_as_json_with_path(cargo_toml_path),
+    Format::Enum => list_enabled_as_enum_with_path(const_name, cargo_toml_path),
+  }
+}
+
+fn list_enabled_as_json_with_path(cargo_toml_path: &str) -> String {
+  let enabled_features = list_enabled_with_docs_with_path(cargo_toml_path);
+  let mut buf = String::new();
+  buf.push('[');
+  for (i, (feature, doc)) in enabled_features.iter().enumerate() {
+    if i > 0 {
+      buf.push(',');
+    }
+    let feature = escape_json_string(feature);
+    let doc = escape_json_string(doc);
+    write!(buf, r#"{{"feature":"{feature}","doc":"{doc}"}}"#).unwrap();
+  }
+  buf.push(']');
+  buf
+}
+
+// Escapes `s` per the JSON spec so it can be embedded in a `"..."` JSON string: `"` and `\` are
+// backslash-escaped, and control characters (a raw, literal newline is illegal in JSON, unlike in
+// a Rust string literal) are escaped either with their short form (`\n`, `\r`, `\t`) or a `\u00XX`
+// sequence. `doc` in particular is free-form prose pulled straight from Cargo.toml comments, so it
+// can't be assumed to already be JSON-safe.
+fn escape_json_string(s: &str) -> String {
+  let mut escaped = String::with_capacity(s.len());
+  for ch in s.chars() {
+    match ch {
+      '\\' => escaped.push_str(r"\\"),
+      '"' => escaped.push_str("\\\""),
+      '\n' => escaped.push_str(r"\n"),
+      '\r' => escaped.push_str(r"\r"),
+      '\t' => escaped.push_str(r"\t"),
+      c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+      c => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+fn list_enabled_as_enum_with_path(const_name: &str, cargo_toml_path: &str) -> String {
+  let enabled_features = list_enabled_with_path(cargo_toml_path);
+  let variants: Vec<String> = enabled_features.iter().map(|feature| to_pascal_case(feature)).collect();
+  check_enum_variant_collisions(&enabled_features, &variants);
+
+  let mut buf = String::new();
+  writeln!(buf, "pub enum {const_name} {{").unwrap();
+  for variant in &variants {
+    writeln!(buf, "  {variant},").unwrap();
+  }
+  writeln!(buf, "}}").unwrap();
+  writeln!(buf).unwrap();
+  writeln!(buf, "impl {const_name} {{").unwrap();
+  writeln!(buf, "  pub fn all() -> &'static [Self] {{").unwrap();
+  write!(buf, "    &[").unwrap();
+  for variant in &variants {
+    write!(buf, "Self::{variant}, ").unwrap();
+  }
+  writeln!(buf, "]").unwrap();
+  writeln!(buf, "  }}").unwrap();
+  writeln!(buf, "}}").unwrap();
+  buf
+}
+
+// Panics with a readable message if two enabled features collapse to the same PascalCase variant
+// name (e.g. `foo-23` and `foo23` both becoming `Foo23`). Left unchecked, that would hand the
+// build script a generated `pub enum` with a duplicate variant, which fails to compile.
+fn check_enum_variant_collisions(features: &[String], variants: &[String]) {
+  let mut seen: HashMap<&str, &str> = HashMap::new();
+  for (feature, variant) in features.iter().zip(variants) {
+    if let Some(previous) = seen.insert(variant.as_str(), feature.as_str()) {
+      panic!(
+        "features `{previous}` and `{feature}` both map to the enum variant `{variant}`; rename one of them to use `Format::Enum`"
+      );
+    }
+  }
+}
+
+// Converts a `kebab-case` or `snake_case` feature name into a `PascalCase` enum variant name.
+fn to_pascal_case(feature: &str) -> String {
+  feature
+    .split(['-', '_'])
+    .map(|segment| {
+      let mut chars = segment.chars();
+      match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+        None => String::new(),
+      }
+    })
+    .collect()
+}
+
+/// Returns the full transitive set of features actually compiled in, not just the directly
+/// enabled ones.
+///
+/// Starting from the directly enabled features (as returned by [`list_enabled`]), this walks the
+/// feature dependency graph (as returned by [`list_graph`]) to also report every feature enabled
+/// indirectly. For instance, if `default` lists `foo`, enabling `default` means `foo` is reported
+/// too, even though Cargo won’t necessarily set `CARGO_FEATURE_FOO` on its own.
+///
+/// This function should only be called in build scripts or code executed during a Cargo build process, as
+/// the required `CARGO_FEATURE_*` environment variables will be missing otherwise.
+///
+/// See also [`list_enabled_transitive_with_path`].
+///
+/// # Panics
+///
+/// Panics if the `Cargo.toml` file cannot be read.
+///
+/// # Returns
+///
+/// A `Vec<String>` containing the names of the enabled features, ordered with `default` first and then sorted alphabetically.
+pub fn list_enabled_transitive() -> Vec<String> {
+  list_enabled_transitive_with_path("Cargo.toml")
+}
+
+/// Returns the full transitive set of features actually compiled in, not just the directly
+/// enabled ones.
+///
+/// Same as [`list_enabled_transitive`] but allows specifying a custom path to `Cargo.toml`.
+///
+/// # Panics
+///
+/// Panics if the specified file cannot be read.
+///
+/// # Arguments
+///
+/// * `cargo_toml_path` - Path to the `Cargo.toml` file
+pub fn list_enabled_transitive_with_path(cargo_toml_path: &str) -> Vec<String> {
+  let all_features = list_all(cargo_toml_path).unwrap();
+  let graph = list_graph(cargo_toml_path).unwrap();
+  let direct = list_enabled_among(&all_features);
+
+  let mut enabled: HashSet<String> = HashSet::new();
+  let mut to_visit = direct;
+  while let Some(feature) = to_visit.pop() {
+    if !enabled.insert(feature.clone()) {
+      continue;
+    }
+    if let Some(deps) = graph.get(&feature) {
+      for dep in deps {
+        // `dep:NAME` only activates the optional dependency `NAME`, never a feature of the same
+        // name, and `CRATE?/FEAT` never enables `CRATE` by itself, so neither walks further into
+        // the graph.
+        let candidate = match dep {
+          FeatureRef::Feature(name) => Some(name),
+          FeatureRef::CrateFeature { krate, .. } => Some(krate),
+          FeatureRef::WeakCrateFeature { .. } => None,
+          FeatureRef::Dependency(_) => None,
+        };
+        if let Some(name) = candidate {
+          if all_features.contains(name) && !enabled.contains(name) {
+            to_visit.push(name.clone());
+          }
+        }
+      }
+    }
+  }
+
+  let mut enabled: Vec<String> = enabled.into_iter().collect();
+  enabled.sort();
+  if let Some(pos) = enabled.iter().position(|f| f == "default") {
+    let default_feature = enabled.remove(pos);
+    enabled.insert(0, default_feature);
+  }
+
+  enabled
+}
+
+/// The kind of problem reported by [`validate_features`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationErrorKind {
+  /// A feature's array references a value that is neither a known feature nor a declared dependency,
+  /// just like Cargo's own "is neither a dependency nor another feature" check.
+  UnknownReference,
+  /// A feature and a dependency (from `[dependencies]`, `[dev-dependencies]` or `[build-dependencies]`)
+  /// share the same name.
+  NameCollision,
+}
+
+/// A single problem found by [`validate_features`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+  /// The feature the problem was found on.
+  pub feature: String,
+  /// The offending reference: the unknown value for [`ValidationErrorKind::UnknownReference`],
+  /// or the colliding dependency name for [`ValidationErrorKind::NameCollision`].
+  pub reference: String,
+  /// The kind of problem.
+  pub kind: ValidationErrorKind,
+}
+
+impl std::fmt::Display for ValidationError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.kind {
+      ValidationErrorKind::UnknownReference => write!(
+        f,
+        "feature `{}` references `{}`, which is neither a dependency nor another feature",
+        self.feature, self.reference
+      ),
+      ValidationErrorKind::NameCollision => write!(
+        f,
+        "feature `{}` has the same name as a dependency",
+        self.feature
+      ),
+    }
+  }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Validates the `[features]` section of a `Cargo.toml` file.
+///
+/// Mirrors the checks Cargo itself performs when loading a manifest: a feature value referencing
+/// something that is neither a declared dependency nor another feature, and a feature sharing its
+/// name with a dependency. Running this from a build script lets a crate catch typos in its feature
+/// wiring without pulling in the full `cargo` toolchain.
+///
+/// # Panics
+///
+/// Panics if the `Cargo.toml` file cannot be read.
+///
+/// # Arguments
+///
+/// * `cargo_toml_path` - Path to the `Cargo.toml` file to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if no problem was found, or `Err` with every [`ValidationError`] found otherwise.
+pub fn validate_features<S: AsRef<str>>(cargo_toml_path: S) -> Result<(), Vec<ValidationError>> {
+  let cargo_toml_path = cargo_toml_path.as_ref();
+  let all_features = list_all(cargo_toml_path).unwrap();
+  let graph = list_graph(cargo_toml_path).unwrap();
+  let dependencies = list_dependencies(cargo_toml_path).unwrap();
+
+  let mut errors = Vec::new();
+
+  for feature in &all_features {
+    if dependencies.contains(feature) {
+      errors.push(ValidationError {
+        feature: feature.clone(),
+        reference: feature.clone(),
+        kind: ValidationErrorKind::NameCollision,
+      });
+    }
+  }
+
+  for (feature, values) in &graph {
+    for value in values {
+      let (is_known, reference) = match value {
+        FeatureRef::Feature(name) => (all_features.contains(name) || dependencies.contains(name), name.clone()),
+        FeatureRef::Dependency(name) => (dependencies.contains(name), format!("dep:{name}")),
+        FeatureRef::CrateFeature { krate, feature: sub } => (dependencies.contains(krate), format!("{krate}/{sub}")),
+        FeatureRef::WeakCrateFeature { krate, feature: sub } => {
+          (dependencies.contains(krate), format!("{krate}?/{sub}"))
+        }
+      };
+
+      if !is_known {
+        errors.push(ValidationError {
+          feature: feature.clone(),
+          reference,
+          kind: ValidationErrorKind::UnknownReference,
+        });
+      }
+    }
+  }
+
+  if errors.is_empty() {
+    return Ok(());
+  }
+
+  errors.sort_by(|a, b| (&a.feature, &a.reference).cmp(&(&b.feature, &b.reference)));
+  Err(errors)
+}
+
 /// Parses a `Cargo.toml` file and returns the set of declared feature names.
 /// 
 /// Only the `[features]` section is considered. While it should be able handle reasonable edge cases, this function also tries to
@@ -144,40 +591,323 @@ pub fn list_all<S: AsRef<str>>(cargo_toml_path: S) -> Result<HashSet<String>, io
   Ok(parse_feature_keys_from_lines(lines))
 }
 
+/// Parses a `Cargo.toml` file and returns the declared features alongside their documentation.
+///
+/// Only the `[features]` section is considered. A feature is documented by placing one or more
+/// consecutive `## ` comment lines immediately above its declaration, following the convention used
+/// by the [document-features](https://crates.io/crates/document-features) crate. Features with no
+/// such comment are still returned, with an empty `String` as their doc.
+///
+/// # Arguments
+///
+/// * `cargo_toml_path` - Path to the `Cargo.toml` file used as the source for the features list.
+///
+/// # Returns
+///
+/// A `Vec<(String, String)>` of `(feature, doc)` pairs, in declaration order.
+pub fn list_docs<S: AsRef<str>>(cargo_toml_path: S) -> Result<Vec<(String, String)>, io::Error> {
+  let file = std::fs::File::open(cargo_toml_path.as_ref())?;
+  let reader = io::BufReader::new(file);
+  let lines: Result<Vec<String>, io::Error> = reader.lines().collect();
+  let lines = lines?;
+  Ok(parse_feature_docs_from_lines(lines))
+}
+
+/// A single value in a feature's array, classified according to Cargo's optional-dependency syntaxes.
+///
+/// # Examples
+///
+/// ```toml
+/// [features]
+/// full = ["foo", "dep:serde", "other-crate/feat", "other-crate?/weak-feat"]
+/// ```
+///
+/// parses `full`'s array as `[Feature("foo"), Dependency("serde"), CrateFeature { krate:
+/// "other-crate", feature: "feat" }, WeakCrateFeature { krate: "other-crate", feature: "weak-feat" }]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeatureRef {
+  /// A plain feature or optional-dependency name, e.g. `"foo"`.
+  Feature(String),
+  /// `dep:NAME` — activates the optional dependency `NAME` without implying a feature of the same name.
+  Dependency(String),
+  /// `CRATE/FEAT` — activates feature `FEAT` on `CRATE`, and unconditionally enables `CRATE`.
+  CrateFeature { krate: String, feature: String },
+  /// `CRATE?/FEAT` — activates feature `FEAT` on `CRATE`, but only if `CRATE` is otherwise enabled.
+  WeakCrateFeature { krate: String, feature: String },
+}
+
+// Classifies a single raw array token into the syntax it uses.
+fn parse_feature_ref(token: &str) -> FeatureRef {
+  if let Some(name) = token.strip_prefix("dep:") {
+    return FeatureRef::Dependency(name.to_string());
+  }
+
+  if let Some((krate, feature)) = token.split_once("?/") {
+    return FeatureRef::WeakCrateFeature {
+      krate: krate.to_string(),
+      feature: feature.to_string(),
+    };
+  }
+
+  if let Some((krate, feature)) = token.split_once('/') {
+    return FeatureRef::CrateFeature {
+      krate: krate.to_string(),
+      feature: feature.to_string(),
+    };
+  }
+
+  FeatureRef::Feature(token.to_string())
+}
+
+/// Parses a `Cargo.toml` file and returns the feature dependency graph.
+///
+/// Only the `[features]` section is considered. Each declared feature maps to the list of
+/// [`FeatureRef`]s it enables, in the order written in its array (whether written inline or split
+/// across multiple lines, as in:
+/// ```toml
+/// big = [
+///   "one",
+///   "two",
+/// ]
+/// ```
+/// ).
+///
+/// # Arguments
+///
+/// * `cargo_toml_path` - Path to the `Cargo.toml` file used as the source for the feature graph.
+///
+/// # Returns
+///
+/// A `HashMap<String, Vec<FeatureRef>>` mapping each feature to the list of [`FeatureRef`]s in its array.
+pub fn list_graph<S: AsRef<str>>(cargo_toml_path: S) -> Result<HashMap<String, Vec<FeatureRef>>, io::Error> {
+  let file = std::fs::File::open(cargo_toml_path.as_ref())?;
+  let reader = io::BufReader::new(file);
+  let lines: Result<Vec<String>, io::Error> = reader.lines().collect();
+  let lines = lines?;
+  Ok(parse_feature_graph_from_lines(lines))
+}
+
+/// Parses a `Cargo.toml` file and returns the set of declared dependency names.
+///
+/// Considers the `[dependencies]`, `[dev-dependencies]` and `[build-dependencies]` tables, including
+/// dependencies declared with the dotted sub-table form (`[dependencies.foo]`).
+///
+/// # Arguments
+///
+/// * `cargo_toml_path` - Path to the `Cargo.toml` file used as the source for the dependency list.
+///
+/// # Returns
+///
+/// A `HashSet<String>` containing the names of the declared dependencies.
+pub fn list_dependencies<S: AsRef<str>>(cargo_toml_path: S) -> Result<HashSet<String>, io::Error> {
+  let file = std::fs::File::open(cargo_toml_path.as_ref())?;
+  let reader = io::BufReader::new(file);
+  let lines: Result<Vec<String>, io::Error> = reader.lines().collect();
+  let lines = lines?;
+  Ok(parse_dependency_names_from_lines(lines))
+}
+
+const DEPENDENCY_SECTIONS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+// Core parser logic that works on any line iterator.
+fn parse_dependency_names_from_lines<I>(lines: I) -> HashSet<String>
+where
+  I: IntoIterator<Item = String>,
+{
+  let mut in_dependencies = false;
+  let mut names = HashSet::new();
+
+  for line in lines {
+    let stripped = line.split('#').next().unwrap_or("").trim();
+
+    if stripped.starts_with('[') {
+      let section = stripped.trim_start_matches('[').trim_end_matches(']');
+
+      if let Some(name) = DEPENDENCY_SECTIONS
+        .iter()
+        .find_map(|table| section.strip_prefix(&format!("{table}.")))
+      {
+        names.insert(name.trim_matches('"').to_string());
+        in_dependencies = false;
+      } else {
+        in_dependencies = DEPENDENCY_SECTIONS.contains(&section);
+      }
+      continue;
+    }
+
+    if in_dependencies && !stripped.is_empty() {
+      if let Some((key, _)) = stripped.split_once('=') {
+        let key = key.trim().trim_matches('"');
+        if !key.is_empty() {
+          names.insert(key.to_string());
+        }
+      }
+    }
+  }
+
+  names
+}
+#[cfg(feature = "test")]
+pub fn test_parse_dependency_names_from_lines<I>(lines: I) -> HashSet<String>
+where
+  I: IntoIterator<Item = String>,
+{
+  parse_dependency_names_from_lines(lines)
+}
+
 // Core parser logic that works on any line iterator.
 fn parse_feature_keys_from_lines<I>(lines: I) -> HashSet<String>
+where
+  I: IntoIterator<Item = String>,
+{
+  parse_feature_docs_from_lines(lines)
+    .into_iter()
+    .map(|(key, _)| key)
+    .collect()
+}
+#[cfg(feature = "test")]
+pub fn test_parse_feature_keys_from_lines<I>(lines: I) -> HashSet<String>
+where
+  I: IntoIterator<Item = String>,
+{
+  parse_feature_keys_from_lines(lines)
+}
+
+// Same as `parse_feature_keys_from_lines`, but also captures the `## ` doc comments documenting
+// each feature. A run of consecutive `## ` lines accumulates into one multi-line description for
+// the feature declared right below; anything in between (a blank line, another section, a line
+// that isn’t a valid `key = value` declaration) discards the pending doc. `### ` lines are plain
+// comments and ignored, while `#! ` lines are free-standing section doc comments, not tied to any
+// particular feature.
+fn parse_feature_docs_from_lines<I>(lines: I) -> Vec<(String, String)>
 where
   I: IntoIterator<Item = String>,
 {
   let mut in_features = false;
-  let mut features = HashSet::new();
+  let mut pending_doc: Vec<String> = Vec::new();
+  let mut docs = Vec::new();
 
   for line in lines {
+    let trimmed = line.trim();
+
+    if trimmed.starts_with("### ") {
+      continue;
+    }
+    if trimmed.starts_with("#! ") {
+      pending_doc.clear();
+      continue;
+    }
+    if let Some(doc_line) = trimmed.strip_prefix("## ") {
+      pending_doc.push(doc_line.to_string());
+      continue;
+    }
+
     let stripped = line.split('#').next().unwrap_or("").trim();
 
     if stripped.starts_with('[') {
       in_features = stripped == "[features]";
+      pending_doc.clear();
       continue;
     }
 
-    if in_features && !stripped.is_empty() {
+    if stripped.is_empty() {
+      pending_doc.clear();
+      continue;
+    }
+
+    if in_features {
       if let Some((key, _)) = stripped.split_once('=') {
         let key = key.trim().trim_matches('"');
         if !key.is_empty() {
-          features.insert(key.to_string());
+          docs.push((key.to_string(), pending_doc.join("\n")));
         }
       }
     }
+    pending_doc.clear();
   }
 
-  features
+  docs
 }
 #[cfg(feature = "test")]
-pub fn test_parse_feature_keys_from_lines<I>(lines: I) -> HashSet<String>
+pub fn test_parse_feature_docs_from_lines<I>(lines: I) -> Vec<(String, String)>
 where
   I: IntoIterator<Item = String>,
 {
-  parse_feature_keys_from_lines(lines)
+  parse_feature_docs_from_lines(lines)
+}
+
+// Same as `parse_feature_keys_from_lines`, but also captures the array of values each feature
+// enables, classified into `FeatureRef`s, rather than throwing them away. The array can either be
+// written inline or span multiple lines; in both cases tokens are accumulated until the closing
+// `]` is found.
+fn parse_feature_graph_from_lines<I>(lines: I) -> HashMap<String, Vec<FeatureRef>>
+where
+  I: IntoIterator<Item = String>,
+{
+  let mut in_features = false;
+  let mut graph = HashMap::new();
+  // When `Some`, we're in the middle of a multi-line array for this feature.
+  let mut pending: Option<(String, Vec<String>)> = None;
+
+  for line in lines {
+    let stripped = line.split('#').next().unwrap_or("").trim();
+
+    if let Some((key, mut values)) = pending.take() {
+      if let Some(idx) = stripped.find(']') {
+        push_tokens(&stripped[..idx], &mut values);
+        graph.insert(key, values.iter().map(|token| parse_feature_ref(token)).collect());
+      } else {
+        push_tokens(stripped, &mut values);
+        pending = Some((key, values));
+      }
+      continue;
+    }
+
+    if stripped.starts_with('[') {
+      in_features = stripped == "[features]";
+      continue;
+    }
+
+    if in_features && !stripped.is_empty() {
+      if let Some((key, value)) = stripped.split_once('=') {
+        let key = key.trim().trim_matches('"');
+        if key.is_empty() {
+          continue;
+        }
+        let key = key.to_string();
+
+        if let Some(rest) = value.trim().strip_prefix('[') {
+          let mut values = Vec::new();
+          if let Some(idx) = rest.find(']') {
+            push_tokens(&rest[..idx], &mut values);
+            graph.insert(key, values.iter().map(|token| parse_feature_ref(token)).collect());
+          } else {
+            push_tokens(rest, &mut values);
+            pending = Some((key, values));
+          }
+        }
+      }
+    }
+  }
+
+  graph
+}
+#[cfg(feature = "test")]
+pub fn test_parse_feature_graph_from_lines<I>(lines: I) -> HashMap<String, Vec<FeatureRef>>
+where
+  I: IntoIterator<Item = String>,
+{
+  parse_feature_graph_from_lines(lines)
+}
+
+// Splits a comma-separated, possibly-quoted list of tokens and appends the non-empty ones to `out`.
+fn push_tokens(s: &str, out: &mut Vec<String>) {
+  for token in s.split(',') {
+    let token = token.trim().trim_matches('"');
+    if !token.is_empty() {
+      out.push(token.to_string());
+    }
+  }
 }
 
 // Returns the list of enabled features that are present in `all_features`.
@@ -210,3 +940,8 @@ fn list_enabled_among(all_features: &std::collections::HashSet<String>) -> Vec<S
 pub fn test_list_enabled_among(all_features: &std::collections::HashSet<String>) -> Vec<String> {
   list_enabled_among(all_features)
 }
+
+#[cfg(feature = "test")]
+pub fn test_to_pascal_case(feature: &str) -> String {
+  to_pascal_case(feature)
+}
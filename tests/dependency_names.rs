@@ -0,0 +1,55 @@
+use list_features::test_parse_dependency_names_from_lines as parse_dependency_names_from_lines;
+
+fn parse_from_str(s: &str) -> std::collections::HashSet<String> {
+  parse_dependency_names_from_lines(s.lines().map(str::to_string))
+}
+
+#[test]
+fn collects_all_three_dependency_tables() {
+  let toml = r#"
+    [dependencies]
+    serde = "1.0"
+
+    [dev-dependencies]
+    criterion = "0.5"
+
+    [build-dependencies]
+    cc = "1.0"
+
+    [features]
+    default = []
+  "#;
+
+  let dependencies = parse_from_str(toml);
+  assert_eq!(dependencies.len(), 3);
+  assert!(dependencies.contains("serde"));
+  assert!(dependencies.contains("criterion"));
+  assert!(dependencies.contains("cc"));
+}
+
+#[test]
+fn ignores_non_dependency_sections() {
+  let toml = r#"
+    [package]
+    name = "example"
+
+    [features]
+    foo = []
+  "#;
+
+  let dependencies = parse_from_str(toml);
+  assert!(dependencies.is_empty());
+}
+
+#[test]
+fn handles_dotted_sub_table_form() {
+  let toml = r#"
+    [dependencies.serde]
+    version = "1.0"
+    features = ["derive"]
+  "#;
+
+  let dependencies = parse_from_str(toml);
+  assert_eq!(dependencies.len(), 1);
+  assert!(dependencies.contains("serde"));
+}
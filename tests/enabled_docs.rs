@@ -0,0 +1,72 @@
+use std::io::Write;
+
+// Each test writes its own throwaway Cargo.toml under the system temp dir, since the functions
+// under test only accept a file path, not a line iterator like the lower-level parsers.
+fn write_temp_cargo_toml(name: &str, contents: &str) -> std::path::PathBuf {
+  let mut path = std::env::temp_dir();
+  path.push(format!("list-features-enabled-docs-{name}-{}.toml", std::process::id()));
+  let mut file = std::fs::File::create(&path).unwrap();
+  file.write_all(contents.as_bytes()).unwrap();
+  path
+}
+
+#[test]
+fn list_enabled_with_docs_pairs_enabled_features_with_their_doc() {
+  let path = write_temp_cargo_toml(
+    "basic",
+    r#"
+      [features]
+      ## Enables foo.
+      foo = []
+      bar = []
+    "#,
+  );
+
+  std::env::set_var("CARGO_FEATURE_FOO", "1");
+  let enabled = list_features::list_enabled_with_docs_with_path(path.to_str().unwrap());
+  std::env::remove_var("CARGO_FEATURE_FOO");
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(enabled, vec![(String::from("foo"), String::from("Enables foo."))]);
+}
+
+#[test]
+fn list_enabled_as_string_with_docs_escapes_quotes_in_doc() {
+  let path = write_temp_cargo_toml(
+    "quoted-doc",
+    r#"
+      [features]
+      ## Has a "quote" in it.
+      foo = []
+    "#,
+  );
+
+  std::env::set_var("CARGO_FEATURE_FOO", "1");
+  let generated = list_features::list_enabled_as_string_with_docs_with_path("ENABLED_FEATURES", path.to_str().unwrap());
+  std::env::remove_var("CARGO_FEATURE_FOO");
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(
+    generated,
+    "pub const ENABLED_FEATURES: &[(&str, &str)] = &[\n(\"foo\", \"Has a \\\"quote\\\" in it.\"),\n];\n"
+  );
+}
+
+#[test]
+fn list_enabled_as_string_with_docs_escapes_backslash_in_doc() {
+  let path = write_temp_cargo_toml(
+    "backslash-doc",
+    r#"
+      [features]
+      ## A path like C:\temp.
+      foo = []
+    "#,
+  );
+
+  std::env::set_var("CARGO_FEATURE_FOO", "1");
+  let generated = list_features::list_enabled_as_string_with_docs_with_path("ENABLED_FEATURES", path.to_str().unwrap());
+  std::env::remove_var("CARGO_FEATURE_FOO");
+  std::fs::remove_file(&path).unwrap();
+
+  assert!(generated.contains(r#"("foo", "A path like C:\\temp.")"#));
+}
@@ -0,0 +1,55 @@
+use std::io::Write;
+
+fn write_temp_cargo_toml(name: &str, contents: &str) -> std::path::PathBuf {
+  let mut path = std::env::temp_dir();
+  path.push(format!("list-features-enabled-transitive-{name}-{}.toml", std::process::id()));
+  let mut file = std::fs::File::create(&path).unwrap();
+  file.write_all(contents.as_bytes()).unwrap();
+  path
+}
+
+#[test]
+fn reports_the_full_chain_behind_a_directly_enabled_feature() {
+  let path = write_temp_cargo_toml(
+    "chain",
+    r#"
+      [features]
+      default = ["foo"]
+      foo = ["bar"]
+      bar = []
+      unrelated = []
+    "#,
+  );
+
+  std::env::set_var("CARGO_FEATURE_DEFAULT", "1");
+  let mut enabled = list_features::list_enabled_transitive_with_path(path.to_str().unwrap());
+  std::env::remove_var("CARGO_FEATURE_DEFAULT");
+  std::fs::remove_file(&path).unwrap();
+
+  // `default` is always reported first; sort the rest to compare regardless of traversal order.
+  assert_eq!(enabled.remove(0), "default");
+  enabled.sort();
+  assert_eq!(enabled, vec![String::from("bar"), String::from("foo")]);
+}
+
+#[test]
+fn does_not_report_features_outside_the_enabled_chain() {
+  let path = write_temp_cargo_toml(
+    "unrelated",
+    r#"
+      [features]
+      default = ["foo"]
+      foo = []
+      unrelated = ["bar"]
+      bar = []
+    "#,
+  );
+
+  std::env::set_var("CARGO_FEATURE_DEFAULT", "1");
+  let enabled = list_features::list_enabled_transitive_with_path(path.to_str().unwrap());
+  std::env::remove_var("CARGO_FEATURE_DEFAULT");
+  std::fs::remove_file(&path).unwrap();
+
+  assert!(!enabled.contains(&String::from("unrelated")));
+  assert!(!enabled.contains(&String::from("bar")));
+}
@@ -0,0 +1,133 @@
+use list_features::test_parse_feature_docs_from_lines as parse_feature_docs_from_lines;
+
+fn parse_from_str(s: &str) -> Vec<(String, String)> {
+  parse_feature_docs_from_lines(s.lines().map(str::to_string))
+}
+
+#[test]
+fn single_line_doc() {
+  let toml = r#"
+    [features]
+    ## Enables foo.
+    foo = []
+  "#;
+
+  let docs = parse_from_str(toml);
+  assert_eq!(docs, vec![(String::from("foo"), String::from("Enables foo."))]);
+}
+
+#[test]
+fn multi_line_doc_accumulates() {
+  let toml = r#"
+    [features]
+    ## Enables foo.
+    ## Also does bar.
+    foo = []
+  "#;
+
+  let docs = parse_from_str(toml);
+  assert_eq!(
+    docs,
+    vec![(String::from("foo"), String::from("Enables foo.\nAlso does bar."))]
+  );
+}
+
+#[test]
+fn feature_without_doc_has_empty_string() {
+  let toml = r#"
+    [features]
+    foo = []
+  "#;
+
+  let docs = parse_from_str(toml);
+  assert_eq!(docs, vec![(String::from("foo"), String::new())]);
+}
+
+#[test]
+fn blank_line_breaks_association() {
+  let toml = r#"
+    [features]
+    ## Enables foo.
+
+    foo = []
+  "#;
+
+  let docs = parse_from_str(toml);
+  assert_eq!(docs, vec![(String::from("foo"), String::new())]);
+}
+
+#[test]
+fn doc_not_followed_by_a_key_is_discarded() {
+  let toml = r#"
+    [features]
+    ## Orphaned doc.
+    [dependencies]
+
+    [features]
+    foo = []
+  "#;
+
+  let docs = parse_from_str(toml);
+  assert_eq!(docs, vec![(String::from("foo"), String::new())]);
+}
+
+#[test]
+fn triple_hash_is_ignored() {
+  let toml = r#"
+    [features]
+    ## Enables foo.
+    ### not a doc comment
+    foo = []
+  "#;
+
+  let docs = parse_from_str(toml);
+  assert_eq!(docs, vec![(String::from("foo"), String::from("Enables foo."))]);
+}
+
+#[test]
+fn bang_comment_is_not_tied_to_a_feature() {
+  let toml = r#"
+    [features]
+    #! Group: core features
+    ## Enables foo.
+    foo = []
+  "#;
+
+  let docs = parse_from_str(toml);
+  assert_eq!(docs, vec![(String::from("foo"), String::from("Enables foo."))]);
+}
+
+#[test]
+fn bang_comment_between_doc_and_key_discards_doc() {
+  let toml = r#"
+    [features]
+    ## Enables foo.
+    #! Group: core features
+    foo = []
+  "#;
+
+  let docs = parse_from_str(toml);
+  assert_eq!(docs, vec![(String::from("foo"), String::new())]);
+}
+
+#[test]
+fn multiple_features_with_and_without_docs() {
+  let toml = r#"
+    [features]
+    ## Enables foo.
+    foo = []
+    bar = []
+    ## Enables baz.
+    baz = ["foo"]
+  "#;
+
+  let docs = parse_from_str(toml);
+  assert_eq!(
+    docs,
+    vec![
+      (String::from("foo"), String::from("Enables foo.")),
+      (String::from("bar"), String::new()),
+      (String::from("baz"), String::from("Enables baz.")),
+    ]
+  );
+}
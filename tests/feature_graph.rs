@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use list_features::{test_parse_feature_graph_from_lines as parse_feature_graph_from_lines, FeatureRef};
+
+fn parse_from_str(s: &str) -> HashMap<String, Vec<FeatureRef>> {
+  parse_feature_graph_from_lines(s.lines().map(str::to_string))
+}
+
+#[test]
+fn empty_array() {
+  let toml = r#"
+    [features]
+    default = []
+  "#;
+
+  let graph = parse_from_str(toml);
+  assert_eq!(graph.get("default"), Some(&Vec::new()));
+}
+
+#[test]
+fn inline_array() {
+  let toml = r#"
+    [features]
+    bar = ["baz", "qux"]
+  "#;
+
+  let graph = parse_from_str(toml);
+  assert_eq!(
+    graph.get("bar"),
+    Some(&vec![FeatureRef::Feature(String::from("baz")), FeatureRef::Feature(String::from("qux"))])
+  );
+}
+
+#[test]
+fn multiline_array() {
+  let toml = r#"
+    [features]
+    big = [
+      "one",
+      "two",
+    ]
+    small = []
+  "#;
+
+  let graph = parse_from_str(toml);
+  assert_eq!(
+    graph.get("big"),
+    Some(&vec![FeatureRef::Feature(String::from("one")), FeatureRef::Feature(String::from("two"))])
+  );
+  assert_eq!(graph.get("small"), Some(&Vec::new()));
+}
+
+#[test]
+fn ignores_other_sections() {
+  let toml = r#"
+    [features]
+    a = ["b"]
+
+    [dependencies]
+    c = "1.0"
+  "#;
+
+  let graph = parse_from_str(toml);
+  assert_eq!(graph.len(), 1);
+  assert_eq!(graph.get("a"), Some(&vec![FeatureRef::Feature(String::from("b"))]));
+}
+
+#[test]
+fn transitive_default_chain() {
+  let toml = r#"
+    [features]
+    default = ["foo"]
+    foo = ["bar"]
+    bar = []
+  "#;
+
+  let graph = parse_from_str(toml);
+  assert_eq!(graph.get("default"), Some(&vec![FeatureRef::Feature(String::from("foo"))]));
+  assert_eq!(graph.get("foo"), Some(&vec![FeatureRef::Feature(String::from("bar"))]));
+  assert_eq!(graph.get("bar"), Some(&Vec::new()));
+}
@@ -0,0 +1,86 @@
+use list_features::{test_parse_feature_graph_from_lines as parse_feature_graph_from_lines, FeatureRef};
+
+fn parse_from_str(s: &str) -> std::collections::HashMap<String, Vec<FeatureRef>> {
+  parse_feature_graph_from_lines(s.lines().map(str::to_string))
+}
+
+#[test]
+fn classifies_dep_colon_syntax() {
+  let toml = r#"
+    [features]
+    full = ["dep:serde"]
+  "#;
+
+  let graph = parse_from_str(toml);
+  assert_eq!(graph.get("full"), Some(&vec![FeatureRef::Dependency(String::from("serde"))]));
+}
+
+#[test]
+fn classifies_crate_slash_feature_syntax() {
+  let toml = r#"
+    [features]
+    full = ["other-crate/feat"]
+  "#;
+
+  let graph = parse_from_str(toml);
+  assert_eq!(
+    graph.get("full"),
+    Some(&vec![FeatureRef::CrateFeature {
+      krate: String::from("other-crate"),
+      feature: String::from("feat"),
+    }])
+  );
+}
+
+#[test]
+fn classifies_weak_crate_slash_feature_syntax() {
+  let toml = r#"
+    [features]
+    full = ["other-crate?/feat"]
+  "#;
+
+  let graph = parse_from_str(toml);
+  assert_eq!(
+    graph.get("full"),
+    Some(&vec![FeatureRef::WeakCrateFeature {
+      krate: String::from("other-crate"),
+      feature: String::from("feat"),
+    }])
+  );
+}
+
+#[test]
+fn classifies_self_feature_slash_subfeature_syntax() {
+  let toml = r#"
+    [features]
+    full = ["self-feature/subfeat"]
+  "#;
+
+  let graph = parse_from_str(toml);
+  assert_eq!(
+    graph.get("full"),
+    Some(&vec![FeatureRef::CrateFeature {
+      krate: String::from("self-feature"),
+      feature: String::from("subfeat"),
+    }])
+  );
+}
+
+#[test]
+fn classifies_mixed_array() {
+  let toml = r#"
+    [features]
+    full = ["foo", "dep:serde", "other-crate/feat", "other-crate?/weak-feat"]
+  "#;
+
+  let graph = parse_from_str(toml);
+  assert_eq!(
+    graph.get("full"),
+    Some(&vec![
+      FeatureRef::Feature(String::from("foo")),
+      FeatureRef::Dependency(String::from("serde")),
+      FeatureRef::CrateFeature { krate: String::from("other-crate"), feature: String::from("feat") },
+      FeatureRef::WeakCrateFeature { krate: String::from("other-crate"), feature: String::from("weak-feat") },
+    ])
+  );
+}
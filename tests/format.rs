@@ -0,0 +1,26 @@
+use list_features::test_to_pascal_case as to_pascal_case;
+
+#[test]
+fn single_word() {
+  assert_eq!(to_pascal_case("foo"), "Foo");
+}
+
+#[test]
+fn dashed_name() {
+  assert_eq!(to_pascal_case("foo-bar"), "FooBar");
+}
+
+#[test]
+fn underscored_name() {
+  assert_eq!(to_pascal_case("foo_bar"), "FooBar");
+}
+
+#[test]
+fn mixed_dashes_and_underscores() {
+  assert_eq!(to_pascal_case("foo-bar_baz"), "FooBarBaz");
+}
+
+#[test]
+fn already_capitalized() {
+  assert_eq!(to_pascal_case("SHOUTing"), "SHOUTing");
+}
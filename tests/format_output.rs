@@ -0,0 +1,129 @@
+use std::io::Write;
+use list_features::Format;
+
+fn write_temp_cargo_toml(name: &str, contents: &str) -> std::path::PathBuf {
+  let mut path = std::env::temp_dir();
+  path.push(format!("list-features-format-output-{name}-{}.toml", std::process::id()));
+  let mut file = std::fs::File::create(&path).unwrap();
+  file.write_all(contents.as_bytes()).unwrap();
+  path
+}
+
+#[test]
+fn json_format_escapes_quotes_and_newlines_in_docs() {
+  let path = write_temp_cargo_toml(
+    "json",
+    r#"
+      [features]
+      ## Has a "quote" and
+      ## a second line.
+      foo = []
+    "#,
+  );
+
+  std::env::set_var("CARGO_FEATURE_FOO", "1");
+  let generated = list_features::list_enabled_as_with_path("UNUSED", Format::Json, path.to_str().unwrap());
+  std::env::remove_var("CARGO_FEATURE_FOO");
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(
+    generated,
+    r#"[{"feature":"foo","doc":"Has a \"quote\" and\na second line."}]"#
+  );
+  // The whole point of the JSON format is a single valid JSON document: no raw control character
+  // (like the literal newline between the two `## ` doc lines) may leak into the output.
+  assert!(!generated.contains('\n'));
+}
+
+#[test]
+fn json_format_with_no_docs() {
+  let path = write_temp_cargo_toml(
+    "json-no-docs",
+    r#"
+      [features]
+      foo = []
+    "#,
+  );
+
+  std::env::set_var("CARGO_FEATURE_FOO", "1");
+  let generated = list_features::list_enabled_as_with_path("UNUSED", Format::Json, path.to_str().unwrap());
+  std::env::remove_var("CARGO_FEATURE_FOO");
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(generated, r#"[{"feature":"foo","doc":""}]"#);
+}
+
+#[test]
+fn slice_format_matches_list_enabled_as_string() {
+  let path = write_temp_cargo_toml(
+    "slice",
+    r#"
+      [features]
+      default = ["foo"]
+      foo = []
+    "#,
+  );
+
+  std::env::set_var("CARGO_FEATURE_DEFAULT", "1");
+  let generated = list_features::list_enabled_as_with_path("ENABLED_FEATURES", Format::Slice, path.to_str().unwrap());
+  std::env::remove_var("CARGO_FEATURE_DEFAULT");
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(generated, "pub const ENABLED_FEATURES: &[&str] = &[\n\"default\",\n];\n");
+}
+
+#[test]
+fn enum_format_generates_one_variant_per_enabled_feature_and_an_all_fn() {
+  let path = write_temp_cargo_toml(
+    "enum",
+    r#"
+      [features]
+      default = ["foo-bar"]
+      foo-bar = []
+    "#,
+  );
+
+  std::env::set_var("CARGO_FEATURE_DEFAULT", "1");
+  let generated = list_features::list_enabled_as_with_path("EnabledFeature", Format::Enum, path.to_str().unwrap());
+  std::env::remove_var("CARGO_FEATURE_DEFAULT");
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(
+    generated,
+    "pub enum EnabledFeature {\n  Default,\n}\n\nimpl EnabledFeature {\n  pub fn all() -> &'static [Self] {\n    &[Self::Default, ]\n  }\n}\n"
+  );
+}
+
+// Removes the temp Cargo.toml and env vars a test set up, even if the test panics partway through.
+struct Cleanup {
+  path: std::path::PathBuf,
+  vars: &'static [&'static str],
+}
+
+impl Drop for Cleanup {
+  fn drop(&mut self) {
+    for var in self.vars {
+      std::env::remove_var(var);
+    }
+    let _ = std::fs::remove_file(&self.path);
+  }
+}
+
+#[test]
+#[should_panic(expected = "both map to the enum variant `Foo23`")]
+fn enum_format_panics_on_variant_name_collision() {
+  let path = write_temp_cargo_toml(
+    "enum-collision",
+    r#"
+      [features]
+      foo-23 = []
+      foo23 = []
+    "#,
+  );
+
+  std::env::set_var("CARGO_FEATURE_FOO_23", "1");
+  std::env::set_var("CARGO_FEATURE_FOO23", "1");
+  let _cleanup = Cleanup { path: path.clone(), vars: &["CARGO_FEATURE_FOO_23", "CARGO_FEATURE_FOO23"] };
+
+  list_features::list_enabled_as_with_path("Demo", Format::Enum, path.to_str().unwrap());
+}
@@ -0,0 +1,139 @@
+use std::io::Write;
+use list_features::{validate_features, ValidationErrorKind};
+
+fn write_temp_cargo_toml(name: &str, contents: &str) -> std::path::PathBuf {
+  let mut path = std::env::temp_dir();
+  path.push(format!("list-features-validate-{name}-{}.toml", std::process::id()));
+  let mut file = std::fs::File::create(&path).unwrap();
+  file.write_all(contents.as_bytes()).unwrap();
+  path
+}
+
+#[test]
+fn clean_manifest_passes() {
+  let path = write_temp_cargo_toml(
+    "clean",
+    r#"
+      [dependencies]
+      serde = "1.0"
+
+      [features]
+      default = ["foo"]
+      foo = ["dep:serde"]
+    "#,
+  );
+
+  let result = validate_features(path.to_str().unwrap());
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn detects_unknown_reference() {
+  let path = write_temp_cargo_toml(
+    "unknown-ref",
+    r#"
+      [features]
+      foo = ["not-a-feature-or-dependency"]
+    "#,
+  );
+
+  let errors = validate_features(path.to_str().unwrap()).unwrap_err();
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(errors.len(), 1);
+  assert_eq!(errors[0].feature, "foo");
+  assert_eq!(errors[0].reference, "not-a-feature-or-dependency");
+  assert_eq!(errors[0].kind, ValidationErrorKind::UnknownReference);
+}
+
+#[test]
+fn detects_name_collision() {
+  let path = write_temp_cargo_toml(
+    "collision",
+    r#"
+      [dependencies]
+      serde = "1.0"
+
+      [features]
+      serde = []
+    "#,
+  );
+
+  let errors = validate_features(path.to_str().unwrap()).unwrap_err();
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(errors.len(), 1);
+  assert_eq!(errors[0].feature, "serde");
+  assert_eq!(errors[0].reference, "serde");
+  assert_eq!(errors[0].kind, ValidationErrorKind::NameCollision);
+}
+
+#[test]
+fn detects_unknown_crate_feature_reference() {
+  let path = write_temp_cargo_toml(
+    "unknown-crate-feature",
+    r#"
+      [features]
+      foo = ["other-crate/feat"]
+    "#,
+  );
+
+  let errors = validate_features(path.to_str().unwrap()).unwrap_err();
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(errors.len(), 1);
+  assert_eq!(errors[0].feature, "foo");
+  assert_eq!(errors[0].reference, "other-crate/feat");
+  assert_eq!(errors[0].kind, ValidationErrorKind::UnknownReference);
+}
+
+#[test]
+fn errors_are_sorted_by_feature_then_reference() {
+  let path = write_temp_cargo_toml(
+    "sorting",
+    r#"
+      [features]
+      zeta = ["unknown-z"]
+      alpha = ["unknown-b", "unknown-a"]
+    "#,
+  );
+
+  let errors = validate_features(path.to_str().unwrap()).unwrap_err();
+  std::fs::remove_file(&path).unwrap();
+
+  let pairs: Vec<(String, String)> = errors.into_iter().map(|e| (e.feature, e.reference)).collect();
+  assert_eq!(
+    pairs,
+    vec![
+      (String::from("alpha"), String::from("unknown-a")),
+      (String::from("alpha"), String::from("unknown-b")),
+      (String::from("zeta"), String::from("unknown-z")),
+    ]
+  );
+}
+
+#[test]
+fn display_formats_unknown_reference_and_name_collision() {
+  let path = write_temp_cargo_toml(
+    "display",
+    r#"
+      [dependencies]
+      serde = "1.0"
+
+      [features]
+      serde = []
+      foo = ["missing"]
+    "#,
+  );
+
+  let errors = validate_features(path.to_str().unwrap()).unwrap_err();
+  std::fs::remove_file(&path).unwrap();
+
+  let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+  assert!(messages.contains(&String::from(
+    "feature `foo` references `missing`, which is neither a dependency nor another feature"
+  )));
+  assert!(messages.contains(&String::from("feature `serde` has the same name as a dependency")));
+}